@@ -0,0 +1,232 @@
+/// Error returned when parsing a checked integer newtype from a string.
+///
+/// Parsing happens in two steps: first the inner integer is parsed (which may fail with a
+/// [`core::num::ParseIntError`]), then the configured checks run (which may fail with the
+/// newtype's own error). This enum keeps the two failure modes distinct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntTypeError<TyErr> {
+  /// The input was not a valid integer for the inner type.
+  Parse(::core::num::ParseIntError),
+  /// The input parsed as an integer but failed the newtype checks.
+  Invalid(TyErr),
+}
+
+/// Define a newtype wrapper for an integer.
+///
+/// This is the integer sibling of [`define_string_type`](crate::define_string_type): it
+/// mirrors the same macro architecture (optional `#[error(const)]`/`#[error(dyn)]` block,
+/// one enum variant per check) but wraps an integer inner type rather than a string.
+///
+/// # Minimal example
+///
+/// ```
+/// use nype::define_int_type;
+///
+/// define_int_type!{
+///   pub struct Percent(u8);
+/// }
+///
+/// let p: Percent = Percent::new(42);
+/// ```
+///
+/// # Full example
+///
+/// ```
+/// use nype::define_int_type;
+///
+/// define_int_type!{
+///   pub struct Port(u16);
+///
+///   #[error(const)]
+///   pub enum PortError {
+///     #[non_zero]
+///     Zero,
+///   }
+/// }
+///
+/// let port: Result<Port, PortError> = Port::new(8080);
+/// ```
+///
+/// The supported checks are `min(N)`, `max(N)`, `range(LO..HI)` (half-open), `multiple_of(N)`,
+/// `non_zero`, `positive`, and `negative`. Every check is const-evaluable, so the const-check
+/// variant generates a `const fn new`.
+#[macro_export]
+macro_rules! define_int_type {
+  // main rule:
+  // 1. Main integer wrapper, as a unit struct wrapping the integer type
+  // 2. (optional) Parse error, each variant is a check
+  (
+    $(#[$struct_meta:meta])*
+    $struct_vis:vis struct $struct_name:ident($inner_ty:ty);
+
+    $(
+      #[error($ck_const:ident)]
+      $(#[$err_meta:meta])*
+      $err_vis:vis enum $err_name:ident {
+        $(
+          #[$($ck_meta:tt)*]
+          $ck_name:ident,
+        )*
+      }
+    )?
+  ) => {
+    $(#[$struct_meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[repr(transparent)]
+    $struct_vis struct $struct_name($inner_ty);
+
+    $(
+      $(#[$err_meta])*
+      #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+      $err_vis enum $err_name {
+        $($ck_name,)*
+      }
+    )?
+
+    // conditional method definition: the constructor signature changes depending on
+    // whether there are checks or not (fallible constructor or not)
+    $crate::define_int_type!(
+      @impl_new $struct_name($inner_ty)
+      $($err_name($ck_const) {
+        $(
+          #[$($ck_meta)*]
+          $ck_name,
+        )*
+      })?
+    );
+
+    impl $struct_name {
+      /// Extract the inner value out of the wrapper.
+      pub const fn into_inner(self) -> $inner_ty {
+        self.0
+      }
+
+      /// Get a reference to the inner value.
+      pub const fn as_inner(&self) -> &$inner_ty {
+        &self.0
+      }
+    }
+  };
+
+  // internal rule for method implementation in the case where there are no checks (all values are valid)
+  (@impl_new $struct_name:ident($inner_ty:ty)) => {
+    impl $struct_name {
+      pub const fn new(inner: $inner_ty) -> Self {
+        Self(inner)
+      }
+    }
+
+    impl ::core::str::FromStr for $struct_name {
+      type Err = ::core::num::ParseIntError;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(<$inner_ty as ::core::str::FromStr>::from_str(s)?))
+      }
+    }
+  };
+
+  // internal rule for method implementation in the case where there are const checks
+  (
+    @impl_new $struct_name:ident($inner_ty:ty)
+    $err_name:ident(const) {
+      $(
+        #[$($ck_meta:tt)*]
+        $ck_name:ident,
+      )*
+    }
+  ) => {
+    impl $struct_name {
+      /// Build a checked integer wrapper in a `const` context.
+      pub const fn new(input: $inner_ty) -> Result<Self, $err_name> {
+        $(
+          $crate::define_int_type!(@check $err_name::$ck_name($($ck_meta)*)(input));
+        )*
+        Ok(Self(input))
+      }
+    }
+
+    impl ::core::str::FromStr for $struct_name {
+      type Err = $crate::int::ParseIntTypeError<$err_name>;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = <$inner_ty as ::core::str::FromStr>::from_str(s)
+          .map_err($crate::int::ParseIntTypeError::Parse)?;
+        Self::new(inner).map_err($crate::int::ParseIntTypeError::Invalid)
+      }
+    }
+  };
+
+  // internal rule for method implementation in the case where there are dyn (non-const) checks
+  (
+    @impl_new $struct_name:ident($inner_ty:ty)
+    $err_name:ident(dyn) {
+      $(
+        #[$($ck_meta:tt)*]
+        $ck_name:ident,
+      )*
+    }
+  ) => {
+    impl $struct_name {
+      /// Build a checked integer wrapper.
+      pub fn new(input: $inner_ty) -> Result<Self, $err_name> {
+        $(
+          $crate::define_int_type!(@check $err_name::$ck_name($($ck_meta)*)(input));
+        )*
+        Ok(Self(input))
+      }
+    }
+
+    impl ::core::str::FromStr for $struct_name {
+      type Err = $crate::int::ParseIntTypeError<$err_name>;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = <$inner_ty as ::core::str::FromStr>::from_str(s)
+          .map_err($crate::int::ParseIntTypeError::Parse)?;
+        Self::new(inner).map_err($crate::int::ParseIntTypeError::Invalid)
+      }
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(min($n:expr))($input:expr)) => {
+    if $input < $n {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(max($n:expr))($input:expr)) => {
+    if $input > $n {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(range($lo:literal..$hi:literal))($input:expr)) => {
+    #[allow(clippy::manual_range_contains)]
+    if $input < $lo || $input >= $hi {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(multiple_of($n:expr))($input:expr)) => {
+    if $input % $n != 0 {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(non_zero)($input:expr)) => {
+    if $input == 0 {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(positive)($input:expr)) => {
+    if $input <= 0 {
+      return Err($err_name::$ck_name);
+    }
+  };
+
+  (@check $err_name:ident::$ck_name:ident(negative)($input:expr)) => {
+    if $input >= 0 {
+      return Err($err_name::$ck_name);
+    }
+  };
+}
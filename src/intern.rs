@@ -0,0 +1,56 @@
+//! Global bidirectional string interner backing the `#[intern]` macro mode.
+//!
+//! The interner associates validated strings with `u32` tags and allows bidirectional
+//! lookup. Strings are leaked on insertion to obtain a `&'static str`, so ids (and the
+//! slices they resolve to) stay valid for the whole program lifetime.
+
+use std::collections::HashMap;
+
+/// A bidirectional string interner.
+///
+/// Equal strings always map to the same id (inserts are deduplicated), and [`resolve`] always
+/// returns exactly the bytes that were interned.
+///
+/// [`resolve`]: Interner::resolve
+#[derive(Debug, Default)]
+pub struct Interner {
+  /// value → id lookup.
+  lookup: HashMap<&'static str, u32>,
+  /// id → value resolution.
+  values: Vec<&'static str>,
+}
+
+impl Interner {
+  /// Create an empty interner.
+  pub fn new() -> Self {
+    Self {
+      lookup: HashMap::new(),
+      values: Vec::new(),
+    }
+  }
+
+  /// Intern a string, returning its id.
+  ///
+  /// If the string was already interned its existing id is returned, so equal strings always
+  /// map to the same id. Otherwise the string is leaked to obtain a `&'static str` and a fresh
+  /// id is allocated.
+  pub fn intern(&mut self, value: &str) -> u32 {
+    if let Some(&id) = self.lookup.get(value) {
+      return id;
+    }
+    let leaked: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    let id = self.values.len() as u32;
+    self.values.push(leaked);
+    self.lookup.insert(leaked, id);
+    id
+  }
+
+  /// Resolve an id back to its interned string slice.
+  ///
+  /// # Panics
+  ///
+  /// Panics if no string has been interned under `id` (it is out of range for this interner).
+  pub fn resolve(&self, id: u32) -> &'static str {
+    self.values[id as usize]
+  }
+}
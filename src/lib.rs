@@ -117,8 +117,15 @@
 //! ## Nype macros
 //!
 //! Nype defines the following macros:
-//! - [`define_new_string`]: Define a string-like newtype wrapper.
+//! - [`define_string_type`]: Define a string-like newtype wrapper.
+//! - [`define_int_type`]: Define an integer newtype wrapper with range checks.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[macro_use]
 pub mod string;
+
+#[macro_use]
+pub mod int;
+
+#[cfg(feature = "intern")]
+pub mod intern;
@@ -26,6 +26,53 @@
 ///
 /// let title: Result<BlogTitle<&'static str>, BlogTitleParseError> = BlogTitle::new("Announcing Strype!");
 /// ```
+///
+/// # Adjusters
+///
+/// An optional `#[adjust]` section normalizes the input before any `@check` runs,
+/// following the adjust-then-validate pattern. Built-in adjusters are `trim`,
+/// `trim_ascii`, `lowercase`, `uppercase`, and (behind the `nfc` feature) `nfc`.
+/// Because adjustment allocates a normalized owned string, adjusters only affect the
+/// owned `new`/`new_box`/`FromStr` paths; the borrowing constructor that would expose the
+/// raw, un-adjusted slice is not generated publicly for adjusted types.
+///
+/// ```ignore
+/// define_string_type!{
+///   pub struct Handle(String);
+///
+///   #[adjust]
+///   { trim, lowercase }
+///
+///   #[error(const)]
+///   pub enum HandleError {
+///     #[non_empty]
+///     NonEmpty,
+///   }
+/// }
+/// ```
+///
+/// # Interning
+///
+/// Behind the `intern` feature, an `#[intern]` section turns the newtype into a small `Copy`
+/// symbol backed by a global bidirectional interner: `new`/`new_ref` run the checks and then
+/// intern the validated slice, storing only a `u32` id inside the wrapper. Equal strings always
+/// map to the same id, so the symbol is `Eq`/`Hash` by id (an `O(1)` integer compare) and cheap
+/// to pass around; `as_str` resolves back through the interner. Ids are only meaningful within
+/// a single type: they are not comparable across differently-configured newtypes.
+///
+/// ```ignore
+/// define_string_type!{
+///   pub struct Symbol(String);
+///
+///   #[intern]
+///
+///   #[error(const)]
+///   pub enum SymbolError {
+///     #[non_empty]
+///     NonEmpty,
+///   }
+/// }
+/// ```
 #[macro_export]
 macro_rules! define_string_type {
   // main rule:
@@ -36,6 +83,11 @@ macro_rules! define_string_type {
     $(#[$struct_meta:meta])*
     $struct_vis:vis struct $struct_name:ident($inner_ty:ty);
 
+    $(
+      #[adjust]
+      { $($adj_name:ident),* $(,)? }
+    )?
+
     $(
       #[error($ck_const:ident)]
       $(#[$err_meta:meta])*
@@ -91,6 +143,7 @@ macro_rules! define_string_type {
     // if there are checks or not (fallible constructor or not)
     $crate::define_string_type!(
       @impl_new $struct_name($inner_ty)
+      $(adjust { $($adj_name,)* })?
       $($err_name($ck_const) {
         $(
           #[$($ck_meta)*]
@@ -197,6 +250,54 @@ macro_rules! define_string_type {
     }
   };
 
+  // intern rule: with an `#[intern]` section the wrapper is a small `Copy` symbol (a `u32` id)
+  // backed by a global bidirectional interner instead of the generic `Self<TyInner>` wrapper.
+  // Adjusters are not supported here: interning already copies the validated slice into the
+  // global table, so there is no borrowed-vs-owned distinction to reconcile.
+  (
+    $(#[$struct_meta:meta])*
+    $struct_vis:vis struct $struct_name:ident($inner_ty:ty);
+
+    #[intern]
+
+    $(
+      #[error($ck_const:ident)]
+      $(#[$err_meta:meta])*
+      $err_vis:vis enum $err_name:ident {
+        $(
+          #[$($ck_meta:tt)*]
+          $ck_name:ident,
+        )*
+      }
+    )?
+  ) => {
+    $(#[$struct_meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[repr(transparent)]
+    $struct_vis struct $struct_name(u32);
+
+    $(
+      $(#[$err_meta])*
+      #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+      $err_vis enum $err_name {
+        $($ck_name,)*
+      }
+    )?
+
+    #[cfg(not(feature = "intern"))]
+    compile_error!("the `#[intern]` macro mode requires the `intern` feature to be enabled");
+
+    $crate::define_string_type!(
+      @impl_intern $struct_name
+      $($err_name($ck_const) {
+        $(
+          #[$($ck_meta)*]
+          $ck_name,
+        )*
+      })?
+    );
+  };
+
   // internal rule for method implementation in the case where there are no checks (all strings are valid)
   (@impl_new $struct_name:ident($inner_ty:ty)) => {
     impl<TyInner> $struct_name<TyInner> {
@@ -214,6 +315,8 @@ macro_rules! define_string_type {
         Ok(Self::new(<$inner_ty>::from(s)))
       }
     }
+
+    $crate::define_string_type!(@impl_serde $struct_name unchecked);
   };
 
   // internal rule for method implementation in the case where there are const checks (all strings are not valid)
@@ -266,6 +369,9 @@ macro_rules! define_string_type {
         Self::new(<$inner_ty>::from(s))
       }
     }
+
+    $crate::define_string_type!(@impl_mutate $struct_name $err_name);
+    $crate::define_string_type!(@impl_serde $struct_name checked $err_name);
   };
 
   // internal rule for method implementation in the case where there are dyn (non-const) checks (all strings are not valid)
@@ -307,6 +413,498 @@ macro_rules! define_string_type {
         Self::new(<$inner_ty>::from(s))
       }
     }
+
+    $crate::define_string_type!(@impl_mutate $struct_name $err_name);
+    $crate::define_string_type!(@impl_serde $struct_name checked $err_name);
+  };
+
+  // internal rule for method implementation with an `#[adjust]` section but no checks:
+  // the input is normalized before being wrapped, but wrapping is still infallible.
+  (
+    @impl_new $struct_name:ident($inner_ty:ty)
+    adjust { $($adj_name:ident,)* }
+  ) => {
+    impl<TyInner> $struct_name<TyInner> {
+      /// Build a wrapper, normalizing the input through the `#[adjust]` stage first.
+      ///
+      /// Because adjustment allocates a normalized owned string, this path is only
+      /// available for owned inner types that can be built from a `String`.
+      pub fn new(input: TyInner) -> Self
+        where TyInner: ::core::ops::Deref<Target = str> + ::core::convert::From<String>,
+      {
+        let mut adjusted: String = (*input).to_owned();
+        $(
+          $crate::define_string_type!(@adjust adjusted; $adj_name);
+        )*
+        Self(<TyInner as ::core::convert::From<String>>::from(adjusted))
+      }
+    }
+
+    impl ::core::str::FromStr for $struct_name<$inner_ty> {
+      type Err = ::core::convert::Infallible;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(<$inner_ty>::from(s)))
+      }
+    }
+
+    $crate::define_string_type!(@impl_serde $struct_name unchecked);
+  };
+
+  // internal rule for method implementation with an `#[adjust]` section and const checks.
+  (
+    @impl_new $struct_name:ident($inner_ty:ty)
+    adjust { $($adj_name:ident,)* }
+    $err_name:ident(const) {
+      $(
+        #[$($ck_meta:tt)*]
+        $ck_name:ident,
+      )*
+    }
+  ) => {
+    impl<'s> $struct_name<&'s str> {
+      /// Run the checks on a raw, un-adjusted slice.
+      ///
+      /// Not exposed publicly: `#[adjust]` types normalize before checking, and this
+      /// helper operates on the raw input, so a public caller could construct a wrapper
+      /// that violates the adjusted invariant (e.g. `"  DemurGos  "` next to `"demurgos"`).
+      /// It exists only to let the owned `new`/`new_box` constructors and `try_mutate` below
+      /// reuse the check sequence after they've already normalized the value themselves.
+      const fn new_ref(input: &'s str) -> Result<&'s $struct_name<str>, $err_name> {
+        $(
+          $crate::define_string_type!(@check $err_name::$ck_name($($ck_meta)*)(input));
+        )*
+        Ok(Self(input).transpose())
+      }
+    }
+
+    $crate::define_string_type!(
+      @impl_new_adjust_checked $struct_name($inner_ty)
+      adjust { $($adj_name,)* }
+      $err_name
+    );
+  };
+
+  // internal rule for method implementation with an `#[adjust]` section and dyn checks.
+  (
+    @impl_new $struct_name:ident($inner_ty:ty)
+    adjust { $($adj_name:ident,)* }
+    $err_name:ident(dyn) {
+      $(
+        #[$($ck_meta:tt)*]
+        $ck_name:ident,
+      )*
+    }
+  ) => {
+    impl<'s> $struct_name<&'s str> {
+      /// Run the checks on a raw, un-adjusted slice.
+      ///
+      /// Not exposed publicly: `#[adjust]` types normalize before checking, and this
+      /// helper operates on the raw input, so a public caller could construct a wrapper
+      /// that violates the adjusted invariant (e.g. `"  DemurGos  "` next to `"demurgos"`).
+      /// It exists only to let the owned `new`/`new_box` constructors and `try_mutate` below
+      /// reuse the check sequence after they've already normalized the value themselves.
+      fn new_ref(input: &'s str) -> Result<&'s $struct_name<str>, $err_name> {
+        $(
+          $crate::define_string_type!(@check $err_name::$ck_name($($ck_meta)*)(input));
+        )*
+        Ok(Self(input).transpose())
+      }
+    }
+
+    $crate::define_string_type!(
+      @impl_new_adjust_checked $struct_name($inner_ty)
+      adjust { $($adj_name,)* }
+      $err_name
+    );
+  };
+
+  // shared owned constructors for the checked `#[adjust]` cases. The borrowing `new_ref` is
+  // emitted by the calling rules above (it differs in constness), and operates on the raw
+  // slice: adjustment is fundamentally incompatible with borrowed construction, since you
+  // cannot return a reference to a normalized-but-not-stored string. Only the owned
+  // `new`/`new_box`/`FromStr` paths normalize before running the checks.
+  (
+    @impl_new_adjust_checked $struct_name:ident($inner_ty:ty)
+    adjust { $($adj_name:ident,)* }
+    $err_name:ident
+  ) => {
+    impl<TyInner> $struct_name<TyInner> {
+      /// Build a wrapper, normalizing the input through the `#[adjust]` stage before
+      /// running the checks on the adjusted value.
+      pub fn new(input: TyInner) -> Result<Self, $err_name>
+        where TyInner: ::core::ops::Deref<Target = str> + ::core::convert::From<String>,
+      {
+        let mut adjusted: String = (*input).to_owned();
+        $(
+          $crate::define_string_type!(@adjust adjusted; $adj_name);
+        )*
+        match $struct_name::new_ref(&adjusted) {
+          Ok(_) => Ok(Self(<TyInner as ::core::convert::From<String>>::from(adjusted))),
+          Err(e) => Err(e),
+        }
+      }
+    }
+
+    impl $struct_name<Box<str>> {
+      /// Build a boxed wrapper, normalizing the input through the `#[adjust]` stage first.
+      pub fn new_box(input: Box<str>) -> Result<Box<$struct_name<str>>, $err_name> {
+        let mut adjusted: String = input.into_string();
+        $(
+          $crate::define_string_type!(@adjust adjusted; $adj_name);
+        )*
+        match $struct_name::new_ref(&adjusted) {
+          Ok(_) => Ok(Self(adjusted.into_boxed_str()).transpose()),
+          Err(e) => Err(e),
+        }
+      }
+    }
+
+    impl ::core::str::FromStr for $struct_name<$inner_ty> {
+      type Err = $err_name;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(<$inner_ty>::from(s))
+      }
+    }
+
+    $crate::define_string_type!(
+      @impl_mutate_adjust $struct_name $err_name
+      adjust { $($adj_name,)* }
+    );
+    $crate::define_string_type!(@impl_serde $struct_name checked $err_name);
+  };
+
+  // internal rule emitting guarded in-place mutation for the owned variants. The checks are
+  // only available when an `#[error(...)]` block is present, so this rule is called from the
+  // checked `@impl_new` cases. The closure is handed `&mut` access to the inner owned string;
+  // after it returns the check sequence is re-run on the mutated value, and the original is
+  // restored if any check fails, so the wrapper is never observed in an invalid state.
+  (@impl_mutate $struct_name:ident $err_name:ident) => {
+    impl $struct_name<String> {
+      /// Edit the validated value in place, re-running the checks afterwards.
+      ///
+      /// On failure the mutation is rolled back and the error is returned, so the invariant
+      /// always holds once this returns.
+      pub fn try_mutate<F>(&mut self, f: F) -> Result<(), $err_name>
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        // Mutate a clone rather than `self.0` directly: if `f` panics, `self.0` must still
+        // hold the original, valid value instead of being left half-mutated.
+        let mut owned = self.0.clone();
+        f(&mut owned);
+        match $struct_name::new_ref(&owned) {
+          Ok(_) => {
+            self.0 = owned;
+            Ok(())
+          }
+          Err(e) => Err(e),
+        }
+      }
+
+      /// Edit the validated value in place, panicking if the result is invalid.
+      pub fn mutate<F>(&mut self, f: F)
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        self.try_mutate(f).expect("mutation must preserve the newtype invariant");
+      }
+    }
+
+    impl $struct_name<Box<str>> {
+      /// Edit the validated value in place, re-running the checks afterwards.
+      ///
+      /// On failure the mutation is rolled back and the error is returned, so the invariant
+      /// always holds once this returns.
+      pub fn try_mutate<F>(&mut self, f: F) -> Result<(), $err_name>
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        // Mutate a clone rather than `self.0` directly: if `f` panics, `self.0` must still
+        // hold the original, valid value instead of being left empty or half-mutated.
+        let mut owned: String = self.0.clone().into_string();
+        f(&mut owned);
+        match $struct_name::new_ref(&owned) {
+          Ok(_) => {
+            self.0 = owned.into_boxed_str();
+            Ok(())
+          }
+          Err(e) => Err(e),
+        }
+      }
+
+      /// Edit the validated value in place, panicking if the result is invalid.
+      pub fn mutate<F>(&mut self, f: F)
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        self.try_mutate(f).expect("mutation must preserve the newtype invariant");
+      }
+    }
+  };
+
+  // internal rule emitting guarded in-place mutation for `#[adjust]` types. Like
+  // `@impl_mutate`, but the `#[adjust]` stage is re-applied to the mutated value before the
+  // checks run, so `try_mutate` cannot be used to smuggle in a value that violates the
+  // normalization the type promises (e.g. appending mixed-case text to a lowercased handle).
+  (
+    @impl_mutate_adjust $struct_name:ident $err_name:ident
+    adjust { $($adj_name:ident,)* }
+  ) => {
+    impl $struct_name<String> {
+      /// Edit the validated value in place, re-normalizing and re-running the checks afterwards.
+      ///
+      /// On failure the mutation is rolled back and the error is returned, so the invariant
+      /// always holds once this returns.
+      pub fn try_mutate<F>(&mut self, f: F) -> Result<(), $err_name>
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        // Mutate a clone rather than `self.0` directly: if `f` panics, `self.0` must still
+        // hold the original, valid value instead of being left half-mutated.
+        let mut owned = self.0.clone();
+        f(&mut owned);
+        $(
+          $crate::define_string_type!(@adjust owned; $adj_name);
+        )*
+        match $struct_name::new_ref(&owned) {
+          Ok(_) => {
+            self.0 = owned;
+            Ok(())
+          }
+          Err(e) => Err(e),
+        }
+      }
+
+      /// Edit the validated value in place, panicking if the result is invalid.
+      pub fn mutate<F>(&mut self, f: F)
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        self.try_mutate(f).expect("mutation must preserve the newtype invariant");
+      }
+    }
+
+    impl $struct_name<Box<str>> {
+      /// Edit the validated value in place, re-normalizing and re-running the checks afterwards.
+      ///
+      /// On failure the mutation is rolled back and the error is returned, so the invariant
+      /// always holds once this returns.
+      pub fn try_mutate<F>(&mut self, f: F) -> Result<(), $err_name>
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        // Mutate a clone rather than `self.0` directly: if `f` panics, `self.0` must still
+        // hold the original, valid value instead of being left empty or half-mutated.
+        let mut owned: String = self.0.clone().into_string();
+        f(&mut owned);
+        $(
+          $crate::define_string_type!(@adjust owned; $adj_name);
+        )*
+        match $struct_name::new_ref(&owned) {
+          Ok(_) => {
+            self.0 = owned.into_boxed_str();
+            Ok(())
+          }
+          Err(e) => Err(e),
+        }
+      }
+
+      /// Edit the validated value in place, panicking if the result is invalid.
+      pub fn mutate<F>(&mut self, f: F)
+        where F: ::core::ops::FnOnce(&mut String),
+      {
+        self.try_mutate(f).expect("mutation must preserve the newtype invariant");
+      }
+    }
+  };
+
+  // internal rule emitting the feature-gated serde integration for the owned variants.
+  //
+  // `Serialize` is transparent: the newtype serializes exactly like the wrapped `str`.
+  // `Deserialize` routes the deserialized owned value through `new` so the configured
+  // checks run, surfacing any failure as a `serde::de::Error` via `custom(...)`.
+  (@impl_serde $struct_name:ident unchecked) => {
+    $crate::define_string_type!(@impl_serialize $struct_name);
+
+    #[cfg(feature = "serde")]
+    impl<'de> ::serde::Deserialize<'de> for $struct_name<String> {
+      fn deserialize<TyDe>(deserializer: TyDe) -> Result<Self, TyDe::Error>
+        where TyDe: ::serde::Deserializer<'de>,
+      {
+        let inner = <String as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+        Ok($struct_name::new(inner))
+      }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> ::serde::Deserialize<'de> for $struct_name<Box<str>> {
+      fn deserialize<TyDe>(deserializer: TyDe) -> Result<Self, TyDe::Error>
+        where TyDe: ::serde::Deserializer<'de>,
+      {
+        let inner = <String as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+        Ok($struct_name::new(inner.into_boxed_str()))
+      }
+    }
+  };
+
+  (@impl_serde $struct_name:ident checked $err_name:ident) => {
+    $crate::define_string_type!(@impl_serialize $struct_name);
+
+    #[cfg(feature = "serde")]
+    impl<'de> ::serde::Deserialize<'de> for $struct_name<String> {
+      fn deserialize<TyDe>(deserializer: TyDe) -> Result<Self, TyDe::Error>
+        where TyDe: ::serde::Deserializer<'de>,
+      {
+        let inner = <String as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+        $struct_name::new(inner).map_err(|e| <TyDe::Error as ::serde::de::Error>::custom(::core::format_args!("{e:?}")))
+      }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> ::serde::Deserialize<'de> for $struct_name<Box<str>> {
+      fn deserialize<TyDe>(deserializer: TyDe) -> Result<Self, TyDe::Error>
+        where TyDe: ::serde::Deserializer<'de>,
+      {
+        let inner = <String as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+        $struct_name::new(inner.into_boxed_str()).map_err(|e| <TyDe::Error as ::serde::de::Error>::custom(::core::format_args!("{e:?}")))
+      }
+    }
+  };
+
+  // transparent `Serialize`, shared by the checked and unchecked cases: delegate to the
+  // inner `str` so the newtype serializes exactly like the wrapped string.
+  (@impl_serialize $struct_name:ident) => {
+    #[cfg(feature = "serde")]
+    impl<TyInner: ?Sized + ::core::convert::AsRef<str>> ::serde::Serialize for $struct_name<TyInner> {
+      fn serialize<TySer>(&self, serializer: TySer) -> Result<TySer::Ok, TySer::Error>
+        where TySer: ::serde::Serializer,
+      {
+        serializer.serialize_str(self.0.as_ref())
+      }
+    }
+  };
+
+  // internal rules emitting the interner-backed symbol methods. The shared accessor/resolver
+  // is factored into `@impl_intern_shared`; the checked and unchecked cases differ only in
+  // whether the constructors run the check sequence before interning.
+  (@impl_intern $struct_name:ident) => {
+    $crate::define_string_type!(@impl_intern_shared $struct_name);
+
+    #[cfg(feature = "intern")]
+    impl $struct_name {
+      /// Intern a string, returning a cheap `Copy` symbol. Every string is valid here.
+      pub fn new(input: &str) -> Self {
+        Self($struct_name::interner().write().expect("interner lock poisoned").intern(input))
+      }
+
+      /// Intern a borrowed string slice; alias of [`new`](Self::new) kept for symmetry with the
+      /// checked variants.
+      pub fn new_ref(input: &str) -> Self {
+        Self::new(input)
+      }
+    }
+
+    #[cfg(feature = "intern")]
+    impl ::core::str::FromStr for $struct_name {
+      type Err = ::core::convert::Infallible;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+      }
+    }
+  };
+
+  (
+    @impl_intern $struct_name:ident
+    $err_name:ident($ck_const:ident) {
+      $(
+        #[$($ck_meta:tt)*]
+        $ck_name:ident,
+      )*
+    }
+  ) => {
+    $crate::define_string_type!(@impl_intern_shared $struct_name);
+
+    #[cfg(feature = "intern")]
+    impl $struct_name {
+      /// Validate and intern a string, returning a cheap `Copy` symbol.
+      pub fn new(input: &str) -> Result<Self, $err_name> {
+        $struct_name::new_ref(input)
+      }
+
+      /// Validate and intern a borrowed string slice.
+      ///
+      /// The checks run on the raw slice first, so only validated strings ever reach the
+      /// global table.
+      pub fn new_ref(input: &str) -> Result<Self, $err_name> {
+        $(
+          $crate::define_string_type!(@check $err_name::$ck_name($($ck_meta)*)(input));
+        )*
+        Ok(Self($struct_name::interner().write().expect("interner lock poisoned").intern(input)))
+      }
+    }
+
+    #[cfg(feature = "intern")]
+    impl ::core::str::FromStr for $struct_name {
+      type Err = $err_name;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+      }
+    }
+  };
+
+  // shared interner accessor and resolution helpers for both intern cases.
+  (@impl_intern_shared $struct_name:ident) => {
+    #[cfg(feature = "intern")]
+    impl $struct_name {
+      /// The global interner backing this symbol type, initialized on first use.
+      fn interner() -> &'static ::std::sync::RwLock<$crate::intern::Interner> {
+        static INTERNER: ::std::sync::LazyLock<::std::sync::RwLock<$crate::intern::Interner>> =
+          ::std::sync::LazyLock::new(|| ::std::sync::RwLock::new($crate::intern::Interner::new()));
+        &INTERNER
+      }
+
+      /// Resolve the symbol back to its interned validated slice.
+      pub fn as_str(&self) -> &'static str {
+        $struct_name::resolve(self.0)
+      }
+
+      /// Resolve an interner id back to its validated slice.
+      pub fn resolve(id: u32) -> &'static str {
+        $struct_name::interner().read().expect("interner lock poisoned").resolve(id)
+      }
+
+      /// Get the raw interner id backing this symbol.
+      ///
+      /// Ids are only meaningful within this type and must not be compared across
+      /// differently-configured newtypes.
+      pub const fn id(self) -> u32 {
+        self.0
+      }
+    }
+  };
+
+  (@adjust $acc:ident; trim) => {
+    $acc = $acc.trim().to_owned();
+  };
+
+  (@adjust $acc:ident; trim_ascii) => {
+    $acc = $acc.trim_ascii().to_owned();
+  };
+
+  (@adjust $acc:ident; lowercase) => {
+    $acc = $acc.to_lowercase();
+  };
+
+  (@adjust $acc:ident; uppercase) => {
+    $acc = $acc.to_uppercase();
+  };
+
+  (@adjust $acc:ident; nfc) => {
+    {
+      #[cfg(not(feature = "nfc"))]
+      compile_error!("the `nfc` adjuster requires the `nfc` feature to be enabled");
+      #[cfg(feature = "nfc")]
+      {
+        $acc = ::unicode_normalization::UnicodeNormalization::nfc($acc.as_str()).collect();
+      }
+    }
   };
 
   (@check $err_name:ident::$ck_name:ident(non_empty)($input:expr)) => {
@@ -0,0 +1,62 @@
+use strype::{define_int_type};
+
+#[test]
+fn int_unchecked() {
+  define_int_type! {
+    /// Wrapper for a raw colour channel value.
+    ///
+    /// Every `u8` is a valid channel, so this is only a semantic wrapper.
+    pub struct Channel(u8);
+  }
+
+  const MAX: Channel = Channel::new(255);
+
+  let channel: Channel = Channel::new(128);
+
+  assert_eq!(channel.as_inner(), &128);
+  assert_eq!(channel.into_inner(), 128);
+  assert_eq!(MAX.into_inner(), 255);
+}
+
+#[test]
+fn int_const_checked() {
+  define_int_type! {
+    /// TCP/UDP port, a non-zero 16-bit integer.
+    pub struct Port(u16);
+
+    #[error(const)]
+    pub enum PortError {
+      #[non_zero]
+      Zero,
+    }
+  }
+
+  const HTTP_PORT: Port = match Port::new(80) {
+    Ok(p) => p,
+    Err(_) => panic!("80 is a valid port"),
+  };
+
+  assert_eq!(HTTP_PORT.into_inner(), 80);
+  assert_eq!(Port::new(8080).unwrap().into_inner(), 8080);
+  assert_eq!(Port::new(0), Err(PortError::Zero));
+}
+
+#[test]
+fn int_dyn_checked() {
+  define_int_type! {
+    /// Percentage, an integer in the `0..=100` range.
+    pub struct Percent(u8);
+
+    #[error(dyn)]
+    pub enum PercentError {
+      #[range(0..101)]
+      OutOfRange,
+    }
+  }
+
+  let half: Percent = Percent::new(50).unwrap();
+
+  assert_eq!(half.into_inner(), 50);
+  assert_eq!(Percent::new(100).unwrap().into_inner(), 100);
+  assert_eq!(Percent::new(101), Err(PercentError::OutOfRange));
+}
@@ -0,0 +1,34 @@
+use strype::define_string_type;
+
+#[cfg(feature = "intern")]
+#[test]
+fn string_interned() {
+  define_string_type! {
+    /// Interned non-empty identifier, stored as a cheap `Copy` symbol.
+    pub struct Symbol(String);
+
+    #[intern]
+
+    #[error(const)]
+    pub enum SymbolError {
+      #[non_empty]
+      NonEmpty,
+    }
+  }
+
+  let foo: Symbol = Symbol::new("foo").unwrap();
+  let foo_again: Symbol = Symbol::new("foo").unwrap();
+  let bar: Symbol = Symbol::new("bar").unwrap();
+
+  // Equal strings dedup to the same id, so the symbol is `Copy` and `Eq` by id.
+  assert_eq!(foo, foo_again);
+  assert_ne!(foo, bar);
+  assert_eq!(foo.id(), foo_again.id());
+
+  // `as_str` resolves back to exactly the interned bytes.
+  assert_eq!(foo.as_str(), "foo");
+  assert_eq!(Symbol::resolve(bar.id()), "bar");
+
+  // The checks run before interning.
+  assert_eq!(Symbol::new(""), Err(SymbolError::NonEmpty));
+}
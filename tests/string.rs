@@ -57,6 +57,99 @@ fn string_const_checked() {
   assert_eq!(Username::new(" demurgos "), Err(UsernameError::Trimmed));
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn string_serde_enforces_checks() {
+  define_string_type! {
+    /// Simple username, non-empty 3-20 char string
+    pub struct Username(String);
+
+    #[error(const)]
+    pub enum UsernameError {
+      #[non_empty]
+      NonEmpty,
+      #[min_len(3)]
+      MinLen,
+      #[max_len(20)]
+      MaxLen,
+    }
+  }
+
+  let username: Username = serde_json::from_str(r#""demurgos""#).unwrap();
+  assert_eq!(username.as_str(), "demurgos");
+
+  // The newtype serializes exactly like the wrapped string.
+  assert_eq!(serde_json::to_string(&username).unwrap(), r#""demurgos""#);
+
+  // The checks run during deserialization.
+  assert!(serde_json::from_str::<Username>(r#""no""#).is_err());
+}
+
+#[test]
+fn string_try_mutate() {
+  define_string_type! {
+    /// Simple username, non-empty 3-20 char string
+    pub struct Username(String);
+
+    #[error(const)]
+    pub enum UsernameError {
+      #[non_empty]
+      NonEmpty,
+      #[min_len(3)]
+      MinLen,
+      #[max_len(20)]
+      MaxLen,
+    }
+  }
+
+  let mut username: Username = Username::new(String::from("demurgos")).unwrap();
+
+  // A valid edit is kept.
+  assert_eq!(username.try_mutate(|s| s.push_str("_dev")), Ok(()));
+  assert_eq!(username.as_str(), "demurgos_dev");
+
+  // An invalid edit is rolled back and reported.
+  assert_eq!(
+    username.try_mutate(|s| s.truncate(1)),
+    Err(UsernameError::MinLen)
+  );
+  assert_eq!(username.as_str(), "demurgos_dev");
+}
+
+#[test]
+fn string_adjusted() {
+  define_string_type! {
+    /// Case-insensitive handle: trimmed and lowercased before validation
+    pub struct Handle(String);
+
+    #[adjust]
+    { trim, lowercase }
+
+    #[error(const)]
+    pub enum HandleError {
+      #[non_empty]
+      NonEmpty,
+      #[max_len(20)]
+      MaxLen,
+    }
+  }
+
+  let mut handle: Handle = Handle::new(String::from("  DemurGos  ")).unwrap();
+
+  assert_eq!(handle.as_str(), "demurgos");
+  assert_eq!(handle.into_inner(), String::from("demurgos"));
+
+  // Adjustment runs before the checks: a blank input normalizes to empty and is rejected.
+  assert_eq!(Handle::new(String::from("   ")), Err(HandleError::NonEmpty));
+
+  let mut handle: Handle = Handle::new(String::from("demurgos")).unwrap();
+
+  // `try_mutate` re-applies the `#[adjust]` stage before checking, so the invariant holds
+  // even when the closure reintroduces trailing whitespace or mixed case.
+  assert_eq!(handle.try_mutate(|s| s.push_str("  ABC  ")), Ok(()));
+  assert_eq!(handle.as_str(), "demurgos  abc");
+}
+
 #[test]
 fn string_dyn_checked() {
   define_string_type! {